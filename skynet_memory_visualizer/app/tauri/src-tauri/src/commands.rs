@@ -1,50 +1,184 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::config::{self, AppConfig};
+use crate::tray;
+
+const HEALTH_CHECK_PATH: &str = "/health";
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(15);
+const BACKEND_HEALTH_CHANGED_EVENT: &str = "backend-health-changed";
 
-#[derive(Serialize, Deserialize)]
-pub struct AppConfig {
-    pub flask_api_url: String,
-    pub mcp_api_url: String,
-    pub app_version: String,
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub reachable: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: Option<u64>,
+}
+
+impl ServiceStatus {
+    fn same_status(&self, other: &ServiceStatus) -> bool {
+        self.reachable == other.reachable && self.status_code == other.status_code
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BackendHealth {
+    pub flask: ServiceStatus,
+    pub mcp: ServiceStatus,
+}
+
+impl BackendHealth {
+    fn same_status(&self, other: &BackendHealth) -> bool {
+        self.flask.same_status(&other.flask) && self.mcp.same_status(&other.mcp)
+    }
 }
 
 #[tauri::command]
-pub fn get_app_config() -> AppConfig {
-    AppConfig {
-        flask_api_url: "http://localhost:5432".to_string(),
-        mcp_api_url: "http://localhost:3456".to_string(),
-        app_version: "1.0.0".to_string(),
+pub fn get_app_config(config: State<'_, Mutex<AppConfig>>) -> AppConfig {
+    config.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_app_config(
+    app_handle: tauri::AppHandle,
+    config: State<'_, Mutex<AppConfig>>,
+    new_config: AppConfig,
+) -> Result<(), String> {
+    if new_config.flask_api_url.trim().is_empty() || new_config.mcp_api_url.trim().is_empty() {
+        return Err("backend URLs cannot be empty".to_string());
+    }
+
+    config::save_settings(&app_handle, &new_config)?;
+    *config.lock().unwrap() = new_config;
+    Ok(())
+}
+
+async fn probe_service(client: &reqwest::Client, base_url: &str) -> ServiceStatus {
+    let url = format!("{}{}", base_url.trim_end_matches('/'), HEALTH_CHECK_PATH);
+    let start = std::time::Instant::now();
+    match client.get(&url).send().await {
+        Ok(response) => ServiceStatus {
+            reachable: true,
+            status_code: Some(response.status().as_u16()),
+            latency_ms: Some(start.elapsed().as_millis() as u64),
+        },
+        Err(_) => ServiceStatus {
+            reachable: false,
+            status_code: None,
+            latency_ms: None,
+        },
     }
 }
 
+fn status_label(status: &ServiceStatus) -> &'static str {
+    if status.reachable {
+        "up"
+    } else {
+        "down"
+    }
+}
+
+fn tooltip_for_health(health: &BackendHealth) -> String {
+    format!(
+        "Skynet Memory Visualizer\nFlask: {}\nMCP: {}",
+        status_label(&health.flask),
+        status_label(&health.mcp)
+    )
+}
+
+async fn probe_backends(config: &AppConfig) -> BackendHealth {
+    let client = reqwest::Client::builder()
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+
+    let (flask, mcp) = tokio::join!(
+        probe_service(&client, &config.flask_api_url),
+        probe_service(&client, &config.mcp_api_url)
+    );
+
+    BackendHealth { flask, mcp }
+}
+
 #[tauri::command]
-pub async fn check_backend_health() -> Result<bool, String> {
-    // TODO: Implement actual health check
-    // For now, return true
-    Ok(true)
+pub async fn check_backend_health(config: State<'_, Mutex<AppConfig>>) -> Result<BackendHealth, String> {
+    let config = config.lock().unwrap().clone();
+    Ok(probe_backends(&config).await)
+}
+
+// Compares reachability/status only — latency jitters on nearly every probe.
+pub async fn poll_backend_health(app_handle: tauri::AppHandle) {
+    use tauri::Manager;
+
+    let mut last_health: Option<BackendHealth> = None;
+    loop {
+        let config = app_handle
+            .state::<Mutex<AppConfig>>()
+            .lock()
+            .unwrap()
+            .clone();
+        let health = probe_backends(&config).await;
+        let healthy = health.flask.reachable && health.mcp.reachable;
+
+        tray::set_tray_status(&app_handle, &tooltip_for_health(&health), healthy);
+
+        let changed = !matches!(&last_health, Some(last) if last.same_status(&health));
+        if changed {
+            let _ = app_handle.emit_all(BACKEND_HEALTH_CHANGED_EVENT, &health);
+        }
+        last_health = Some(health);
+
+        tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+    }
 }
 
+const ALLOWED_LINK_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Linux desktop environments don't all ship `xdg-open`; fall back through
+/// the common desktop-specific openers before giving up.
+#[cfg(target_os = "linux")]
+const LINUX_OPENERS: &[&str] = &["xdg-open", "gnome-open", "kde-open"];
+
 #[tauri::command]
 pub fn open_external_link(url: String) -> Result<(), String> {
+    let parsed = url::Url::parse(&url).map_err(|e| e.to_string())?;
+    if !ALLOWED_LINK_SCHEMES.contains(&parsed.scheme()) {
+        return Err(format!("refusing to open disallowed scheme: {}", parsed.scheme()));
+    }
+    let url = parsed.as_str();
+
     #[cfg(target_os = "linux")]
     {
-        std::process::Command::new("xdg-open")
-            .arg(&url)
-            .spawn()
-            .map_err(|e| e.to_string())?;
+        let mut last_error = None;
+        for opener in LINUX_OPENERS {
+            match std::process::Command::new(opener).arg(url).spawn() {
+                Ok(_) => return Ok(()),
+                Err(e) => last_error = Some(e.to_string()),
+            }
+        }
+        return Err(last_error.unwrap_or_else(|| "no opener available".to_string()));
     }
     #[cfg(target_os = "macos")]
     {
         std::process::Command::new("open")
-            .arg(&url)
+            .arg(url)
             .spawn()
             .map_err(|e| e.to_string())?;
     }
     #[cfg(target_os = "windows")]
     {
-        std::process::Command::new("cmd")
-            .args(&["/C", "start", &url])
+        // explorer.exe resolves a URL via ShellExecute directly, without
+        // ever handing the argument to cmd.exe's `&`/`|`/`^` shell parser
+        // the way `cmd /C start` does.
+        std::process::Command::new("explorer")
+            .arg(url)
             .spawn()
             .map_err(|e| e.to_string())?;
     }
+    #[allow(unreachable_code)]
     Ok(())
 }
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub flask_api_url: String,
+    pub mcp_api_url: String,
+    pub app_version: String,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            flask_api_url: "http://localhost:5432".to_string(),
+            mcp_api_url: "http://localhost:3456".to_string(),
+            app_version: "1.0.0".to_string(),
+        }
+    }
+}
+
+impl AppConfig {
+    fn apply_env_overrides(mut self) -> Self {
+        if let Ok(flask_url) = std::env::var("SKYNET_FLASK_URL") {
+            self.flask_api_url = flask_url;
+        }
+        if let Ok(mcp_url) = std::env::var("SKYNET_MCP_URL") {
+            self.mcp_api_url = mcp_url;
+        }
+        self
+    }
+}
+
+fn config_file_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app_handle
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "could not resolve app config directory".to_string())?;
+    Ok(config_dir.join(CONFIG_FILE_NAME))
+}
+
+pub fn load_settings(app_handle: &tauri::AppHandle) -> Result<AppConfig, String> {
+    let path = config_file_path(app_handle)?;
+
+    let config = if path.exists() {
+        let raw = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&raw).map_err(|e| e.to_string())?
+    } else {
+        let config = AppConfig::default();
+        save_settings(app_handle, &config)?;
+        config
+    };
+
+    Ok(config.apply_env_overrides())
+}
+
+// Write-then-rename so a crash mid-write can't leave a corrupt config file.
+pub fn save_settings(app_handle: &tauri::AppHandle, config: &AppConfig) -> Result<(), String> {
+    let path = config_file_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let serialized = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serialized).map_err(|e| e.to_string())?;
+    fs::rename(&tmp_path, &path).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
@@ -1,20 +1,84 @@
-// Prevents additional console window on Windows in release
-#![cfg_attr(
-    all(not(debug_assertions), target_os = "windows"),
-    windows_subsystem = "windows"
-)]
-
-mod commands;
-
-use commands::*;
-
-fn main() {
-    tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![
-            get_app_config,
-            check_backend_health,
-            open_external_link
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
-}
+// Prevents additional console window on Windows in release
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+mod commands;
+mod config;
+mod protocol;
+mod tray;
+
+use std::sync::Mutex;
+
+use commands::*;
+use config::AppConfig;
+use serde::Serialize;
+use tauri::{Manager, WindowEvent};
+
+#[derive(Clone, Serialize)]
+struct SingleInstancePayload {
+    args: Vec<String>,
+    cwd: String,
+}
+
+fn main() {
+    if let Err(err) = run() {
+        show_startup_error(&err.to_string());
+        std::process::exit(1);
+    }
+}
+
+fn run() -> tauri::Result<()> {
+    tauri::Builder::default()
+        // Managed before the window (and any skynet:// request it can fire)
+        // exists, so protocol::handle never reads unmanaged state; setup()
+        // below overwrites this with the config loaded from disk.
+        .manage(Mutex::new(AppConfig::default()))
+        .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            if let Some(window) = app.get_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+                let _ = window.emit("single-instance", SingleInstancePayload { args, cwd });
+            }
+        }))
+        .invoke_handler(tauri::generate_handler![
+            get_app_config,
+            set_app_config,
+            check_backend_health,
+            open_external_link
+        ])
+        .system_tray(tray::build_tray())
+        .on_system_tray_event(|app, event| tray::handle_tray_event(app, event))
+        .register_uri_scheme_protocol(protocol::SCHEME, protocol::handle)
+        .setup(|app| {
+            let handle = app.handle();
+            let app_config = config::load_settings(&handle)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            *app.state::<Mutex<AppConfig>>().lock().unwrap() = app_config;
+
+            tauri::async_runtime::spawn(poll_backend_health(handle));
+            Ok(())
+        })
+        .on_window_event(|event| {
+            // Close-to-tray: keep the window (and the background health
+            // poller) alive in the background instead of exiting the app.
+            if let WindowEvent::CloseRequested { api, .. } = event.event() {
+                event.window().hide().ok();
+                api.prevent_close();
+            }
+        })
+        .run(tauri::generate_context!())
+}
+
+// The console is suppressed on packaged Windows builds, so a setup failure
+// needs this dialog or it vanishes with no window ever appearing.
+fn show_startup_error(message: &str) {
+    tauri::api::dialog::blocking::MessageDialogBuilder::new(
+        "Skynet Memory Visualizer failed to start",
+        message,
+    )
+    .kind(tauri::api::dialog::MessageDialogKind::Error)
+    .buttons(tauri::api::dialog::MessageDialogButtons::Ok)
+    .show();
+}
@@ -0,0 +1,52 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::http::{Request, Response, ResponseBuilder};
+use tauri::{AppHandle, Manager};
+
+use crate::config::AppConfig;
+
+pub const SCHEME: &str = "skynet";
+const UPSTREAM_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub fn handle(
+    app_handle: &AppHandle,
+    request: &Request,
+) -> Result<Response, Box<dyn std::error::Error>> {
+    let uri: http::Uri = request.uri().parse()?;
+    let service = uri.host().ok_or("missing service host in skynet:// URL")?;
+
+    let config = app_handle.state::<Mutex<AppConfig>>().lock().unwrap().clone();
+    let base_url = match service {
+        "flask" => &config.flask_api_url,
+        "mcp" => &config.mcp_api_url,
+        other => return Err(format!("unknown skynet:// service: {other}").into()),
+    };
+
+    let mut upstream_url = format!("{}{}", base_url.trim_end_matches('/'), uri.path());
+    if let Some(query) = uri.query() {
+        upstream_url.push('?');
+        upstream_url.push_str(query);
+    }
+
+    let (status, mime_type, body) = tauri::async_runtime::block_on(fetch_upstream(&upstream_url))?;
+
+    ResponseBuilder::new()
+        .status(status)
+        .mimetype(&mime_type)
+        .body(body)
+}
+
+async fn fetch_upstream(url: &str) -> Result<(u16, String, Vec<u8>), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::builder().timeout(UPSTREAM_TIMEOUT).build()?;
+    let upstream = client.get(url).send().await?;
+    let status = upstream.status().as_u16();
+    let mime_type = upstream
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+    let body = upstream.bytes().await?.to_vec();
+    Ok((status, mime_type, body))
+}
@@ -0,0 +1,58 @@
+use tauri::{AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu};
+
+const TRAY_SHOW: &str = "tray_show";
+const TRAY_HIDE: &str = "tray_hide";
+const TRAY_CHECK_BACKEND: &str = "tray_check_backend";
+const TRAY_QUIT: &str = "tray_quit";
+
+pub fn build_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(TRAY_SHOW, "Show"))
+        .add_item(CustomMenuItem::new(TRAY_HIDE, "Hide"))
+        .add_item(CustomMenuItem::new(TRAY_CHECK_BACKEND, "Check backend"))
+        .add_item(CustomMenuItem::new(TRAY_QUIT, "Quit"));
+
+    SystemTray::new().with_menu(menu).with_tooltip("Skynet Memory Visualizer")
+}
+
+pub fn handle_tray_event(app_handle: &AppHandle, event: SystemTrayEvent) {
+    let SystemTrayEvent::MenuItemClick { id, .. } = event else {
+        return;
+    };
+
+    let Some(window) = app_handle.get_window("main") else {
+        return;
+    };
+
+    match id.as_str() {
+        TRAY_SHOW => {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+        TRAY_HIDE => {
+            let _ = window.hide();
+        }
+        TRAY_CHECK_BACKEND => {
+            let _ = window.show();
+            let _ = window.set_focus();
+            let _ = window.emit("tray-check-backend-requested", ());
+        }
+        TRAY_QUIT => {
+            app_handle.exit(0);
+        }
+        _ => {}
+    }
+}
+
+const ICON_HEALTHY: &str = "icons/tray-healthy.png";
+const ICON_UNHEALTHY: &str = "icons/tray-unhealthy.png";
+
+pub fn set_tray_status(app_handle: &AppHandle, tooltip: &str, healthy: bool) {
+    let tray_handle = app_handle.tray_handle();
+    let _ = tray_handle.set_tooltip(tooltip);
+
+    let icon_path = if healthy { ICON_HEALTHY } else { ICON_UNHEALTHY };
+    if let Some(icon_path) = app_handle.path_resolver().resolve_resource(icon_path) {
+        let _ = tray_handle.set_icon(tauri::Icon::File(icon_path));
+    }
+}